@@ -0,0 +1,142 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use ff::PrimeField;
+use groupy::CurveProjective;
+use ocl::Device;
+use rayon::prelude::*;
+
+use crate::bls::Engine;
+use crate::gpu::error::{GPUError, GPUResult};
+use crate::gpu::utils::{get_core_count, get_devices};
+use crate::groth16::utils::{MultiscalarPrecomp, PublicInputs, POOL};
+
+/// A registered device plus its relative weight (core count), used to size
+/// the chunks it pulls off the shared work queue.
+struct WeightedDevice {
+    device: Device,
+    weight: usize,
+}
+
+/// Splits a single MSM across every GPU `get_devices` can see, instead of
+/// callers picking one device at a time.
+///
+/// Devices are weighted by `get_core_count` so faster cards pull more
+/// chunks off a shared atomic work queue -- the same trick `par_multiscalar`
+/// uses to keep CPU threads from stalling on a preempted one, just applied
+/// across devices. This turns multi-GPU machines into genuinely parallel
+/// provers instead of serializing on a single device.
+pub struct DeviceScheduler {
+    devices: Vec<WeightedDevice>,
+}
+
+impl DeviceScheduler {
+    /// Discover every device on `platform_name` and weight it by
+    /// `get_core_count`. Devices whose core count can't be determined are
+    /// dropped rather than failing the whole scheduler.
+    pub fn new(platform_name: &str) -> GPUResult<Self> {
+        let devices: Vec<WeightedDevice> = get_devices(platform_name)?
+            .into_iter()
+            .filter_map(|device| match get_core_count(device) {
+                Ok(weight) if weight > 0 => Some(WeightedDevice { device, weight }),
+                _ => None,
+            })
+            .collect();
+
+        if devices.is_empty() {
+            return Err(GPUError::Simple("No usable GPU devices found!"));
+        }
+
+        Ok(DeviceScheduler { devices })
+    }
+
+    /// Number of devices this scheduler will dispatch work to.
+    pub fn device_count(&self) -> usize {
+        self.devices.len()
+    }
+
+    /// Run a multiscalar multiplication over `[0, num_points)` across all
+    /// registered devices, accumulating each device's partial `E::G1` and
+    /// summing them at the end.
+    ///
+    /// `kernel` performs the actual on-device multiscalar over one chunk
+    /// (the device, the chunk's scalars, the precomp table subset starting
+    /// at the chunk, the chunk length, and `nbits`) and is expected to
+    /// internally pick the right OpenCL context for `device`.
+    pub fn multiscalar<E, F>(
+        &self,
+        k: &PublicInputs<'_, E, F>,
+        precomp_table: &dyn MultiscalarPrecomp<E>,
+        num_points: usize,
+        nbits: usize,
+        kernel: &(dyn Fn(
+            &Device,
+            &[<E::Fr as PrimeField>::Repr],
+            &dyn MultiscalarPrecomp<E>,
+            usize,
+            usize,
+        ) -> GPUResult<E::G1>
+              + Sync),
+    ) -> GPUResult<E::G1>
+    where
+        E: Engine,
+        F: Fn(usize) -> <E::Fr as PrimeField>::Repr + Sync + Send,
+    {
+        const BASE_CHUNK: usize = 256; // TUNEABLE, mirrors par_multiscalar's large-input chunk size
+        let min_weight = self.devices.iter().map(|d| d.weight).min().unwrap_or(1).max(1);
+
+        // Work item counter, in units of BASE_CHUNK. Every device advances
+        // this by its own `chunks_per_turn` atomically, so the BASE_CHUNK
+        // indices it reserves never overlap with another device's turn
+        // regardless of per-device weighting -- unlike indexing by
+        // `turn * stride` with a per-device stride, which would let heavier
+        // and lighter cards claim overlapping (or skip uncovered) ranges.
+        let work = AtomicUsize::new(0);
+
+        let partials: Vec<GPUResult<E::G1>> = POOL.install(|| {
+            self.devices
+                .par_iter()
+                .map(|wd| {
+                    // Cards with more cores pull proportionally more base
+                    // chunks per turn at the shared work queue.
+                    let chunks_per_turn = ((wd.weight + min_weight - 1) / min_weight).max(1);
+                    let stride = BASE_CHUNK * chunks_per_turn;
+
+                    let mut scalar_storage = vec![<E::Fr as PrimeField>::Repr::default(); stride];
+                    let mut partial = E::G1::zero();
+
+                    loop {
+                        let first_chunk = work.fetch_add(chunks_per_turn, Ordering::SeqCst);
+                        let start_idx = first_chunk * BASE_CHUNK;
+                        if start_idx >= num_points {
+                            break;
+                        }
+                        let end_idx = (start_idx + stride).min(num_points);
+                        let num_items = end_idx - start_idx;
+
+                        let scalars = match k {
+                            PublicInputs::Slice(s) => &s[start_idx..end_idx],
+                            PublicInputs::Getter(getter) => {
+                                for i in start_idx..end_idx {
+                                    scalar_storage[i - start_idx] = getter(i);
+                                }
+                                &scalar_storage[..num_items]
+                            }
+                        };
+
+                        let subset = precomp_table.at_point(start_idx);
+                        let result = kernel(&wd.device, scalars, &subset, num_items, nbits)?;
+                        partial.add_assign(&result);
+                    }
+
+                    Ok(partial)
+                })
+                .collect()
+        });
+
+        let mut result = E::G1::zero();
+        for partial in partials {
+            result.add_assign(&partial?);
+        }
+        Ok(result)
+    }
+}
@@ -6,40 +6,144 @@ use std::collections::HashMap;
 use std::env;
 
 pub const GPU_NVIDIA_PLATFORM_NAME: &str = "NVIDIA CUDA";
-// pub const CPU_INTEL_PLATFORM_NAME: &str = "Intel(R) CPU Runtime for OpenCL(TM) Applications";
+pub const GPU_AMD_PLATFORM_NAME: &str = "AMD Accelerated Parallel Processing";
+/// Shared prefix of Intel's OpenCL platform names. The CPU runtime reports
+/// exactly `"Intel(R) OpenCL"`, while GPU runtimes append a suffix (e.g.
+/// `"Intel(R) OpenCL HD Graphics"`, `"Intel(R) OpenCL Graphics"`), so
+/// platform lookups match on this as a prefix rather than requiring
+/// equality.
+pub const GPU_INTEL_PLATFORM_NAME: &str = "Intel(R) OpenCL";
 
+/// An OpenCL platform vendor bellperson knows how to drive. Used both to
+/// pick which platform(s) `get_all_devices` enumerates and, in
+/// `get_bus_id`, to pick the raw info code for reading a device's PCI bus
+/// id: AMD devices use `CL_DEVICE_TOPOLOGY_AMD`, all others NVIDIA's
+/// `0x4008`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Vendor {
+    Nvidia,
+    Amd,
+    Intel,
+}
+
+impl Vendor {
+    fn platform_name(self) -> &'static str {
+        match self {
+            Vendor::Nvidia => GPU_NVIDIA_PLATFORM_NAME,
+            Vendor::Amd => GPU_AMD_PLATFORM_NAME,
+            Vendor::Intel => GPU_INTEL_PLATFORM_NAME,
+        }
+    }
+
+    fn all() -> [Vendor; 3] {
+        [Vendor::Nvidia, Vendor::Amd, Vendor::Intel]
+    }
+}
+
+impl std::str::FromStr for Vendor {
+    type Err = GPUError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "nvidia" => Ok(Vendor::Nvidia),
+            "amd" => Ok(Vendor::Amd),
+            "intel" => Ok(Vendor::Intel),
+            _ => Err(GPUError::Simple("Unknown BELLMAN_PLATFORM vendor!")),
+        }
+    }
+}
+
+fn device_vendor(d: Device) -> GPUResult<Vendor> {
+    let vendor = match d.info(ocl::enums::DeviceInfo::Vendor)? {
+        ocl::enums::DeviceInfoResult::Vendor(v) => v.to_string().to_uppercase(),
+        _ => return Err(GPUError::Simple("Cannot extract GPU vendor!")),
+    };
+
+    if vendor.contains("NVIDIA") {
+        Ok(Vendor::Nvidia)
+    } else if vendor.contains("ADVANCED MICRO DEVICES") || vendor.contains("AMD") {
+        Ok(Vendor::Amd)
+    } else if vendor.contains("INTEL") {
+        Ok(Vendor::Intel)
+    } else {
+        Err(GPUError::Simple("Unknown GPU vendor!"))
+    }
+}
+
+fn filter_by_bus_id(devs: Vec<Device>) -> GPUResult<Vec<Device>> {
+    let bus_ids = match env::var("BELLMAN_GPUS") {
+        Ok(v) => v
+            .split(",")
+            .map(|s| s.parse::<u32>().expect("Invalid Bus-Id number!"))
+            .collect::<Vec<u32>>(),
+        Err(_) => return Ok(devs),
+    };
+
+    let mut filtered_devs = Vec::new();
+    for d in devs.iter() {
+        if bus_ids.contains(&get_bus_id(*d)?) {
+            filtered_devs.push(*d);
+        }
+    }
+    Ok(filtered_devs)
+}
+
+/// List the devices on a single named OpenCL platform (e.g.
+/// `GPU_NVIDIA_PLATFORM_NAME`), filtered by `BELLMAN_GPUS` bus ids if set.
 pub fn get_devices(platform_name: &str) -> GPUResult<Vec<Device>> {
     if env::var("BELLMAN_NO_GPU").is_ok() {
         return Err(GPUError::Simple("GPU accelerator is disabled!"));
     }
 
     let platform = Platform::list()?.into_iter().find(|&p| match p.name() {
-        Ok(p) => p == platform_name,
+        Ok(p) => p.starts_with(platform_name),
         Err(_) => false,
     });
 
-    let bus_ids = env::var("BELLMAN_GPUS").map(|v| {
-        v.split(",")
-            .map(|s| s.parse::<u32>().expect("Invalid Bus-Id number!"))
-            .collect::<Vec<u32>>()
-    });
-
     match platform {
-        Some(p) => {
-            let mut devs = Device::list_all(p)?;
-            if let Ok(bus_ids) = bus_ids {
-                let mut filtered_devs = Vec::new();
-                for d in devs.iter() {
-                    if bus_ids.contains(&get_bus_id(*d)?) {
-                        filtered_devs.push(*d);
-                    }
-                }
-                devs = filtered_devs;
+        Some(p) => filter_by_bus_id(Device::list_all(p)?),
+        None => Err(GPUError::Simple("GPU platform not found!")),
+    }
+}
+
+/// List devices across every supported vendor's OpenCL platform (NVIDIA,
+/// AMD, Intel) instead of a single hardcoded one. Set `BELLMAN_PLATFORM`
+/// (e.g. `"amd"`) to restrict discovery to one vendor; otherwise every
+/// platform present on the system is searched, in `Vendor::all()` order.
+/// `BELLMAN_GPUS` bus-id filtering applies to the combined device list,
+/// same as `get_devices`.
+pub fn get_all_devices() -> GPUResult<Vec<Device>> {
+    if env::var("BELLMAN_NO_GPU").is_ok() {
+        return Err(GPUError::Simple("GPU accelerator is disabled!"));
+    }
+
+    let wanted_vendor = match env::var("BELLMAN_PLATFORM") {
+        Ok(v) => Some(v.parse::<Vendor>()?),
+        Err(_) => None,
+    };
+
+    let platforms = Platform::list()?;
+    let mut devs = Vec::new();
+    for vendor in Vendor::all().iter().copied() {
+        if let Some(wanted) = wanted_vendor {
+            if wanted != vendor {
+                continue;
             }
-            Ok(devs)
         }
-        None => Err(GPUError::Simple("GPU platform not found!")),
+        if let Some(&platform) = platforms.iter().find(|p| {
+            p.name()
+                .map(|name| name.starts_with(vendor.platform_name()))
+                .unwrap_or(false)
+        }) {
+            devs.extend(Device::list_all(platform)?);
+        }
     }
+
+    if devs.is_empty() {
+        return Err(GPUError::Simple("GPU platform not found!"));
+    }
+
+    filter_by_bus_id(devs)
 }
 
 lazy_static::lazy_static! {
@@ -81,15 +185,86 @@ lazy_static::lazy_static! {
     };
 }
 
+/// Cores-per-compute-unit multipliers keyed by a case-insensitive substring
+/// of the device name, used to estimate a core count for cards that aren't
+/// in `CORE_COUNTS`. Checked in order, so list newer/narrower architectures
+/// before older/broader ones.
+// Tesla cards have no entry here: V100/P100/T4 are matched by exact name in
+// `CORE_COUNTS`, and any other Tesla falls back to the default of 64 cores
+// per compute unit, which is also what those three architectures have.
+const CORES_PER_COMPUTE_UNIT: &[(&str, usize)] = &[
+    ("RTX 40", 128), // Ada Lovelace
+    ("RTX 30", 128), // Ampere
+    ("RTX 20", 64),  // Turing
+    ("GTX 16", 64),  // Turing
+    ("GTX 10", 128), // Pascal
+    ("TITAN V", 64), // Volta
+];
+
+/// Estimate a core count for a device missing from `CORE_COUNTS` by reading
+/// its compute-unit count and scaling by an architecture-indexed
+/// cores-per-compute-unit constant, falling back to a conservative default
+/// for architectures this table doesn't recognize.
+fn estimate_core_count(d: Device) -> GPUResult<usize> {
+    let compute_units = match d.info(ocl::enums::DeviceInfo::MaxComputeUnits)? {
+        ocl::enums::DeviceInfoResult::MaxComputeUnits(units) => units as usize,
+        _ => return Err(GPUError::Simple("Cannot extract GPU compute units!")),
+    };
+
+    let name = d.name()?.to_uppercase();
+    let cores_per_cu = CORES_PER_COMPUTE_UNIT
+        .iter()
+        .find(|(needle, _)| name.contains(needle))
+        .map(|&(_, cores)| cores)
+        .unwrap_or(64);
+
+    Ok(compute_units * cores_per_cu)
+}
+
 pub fn get_core_count(d: Device) -> GPUResult<usize> {
     match CORE_COUNTS.get(&d.name()?[..]) {
         Some(&cores) => Ok(cores),
-        None => Err(GPUError::Simple("Device unknown!")),
+        None => estimate_core_count(d),
     }
 }
 
+const NVIDIA_BUS_ID_INFO: u32 = 0x4008;
+const AMD_TOPOLOGY_AMD_INFO: u32 = 0x4037; // CL_DEVICE_TOPOLOGY_AMD
+
+// Layout of `cl_device_topology_amd`, as defined by the AMD APP SDK's
+// `cl_ext.h` for `CL_DEVICE_TOPOLOGY_AMD`:
+//
+//   typedef union {
+//       struct {
+//           cl_uint type;          // 4 bytes
+//           cl_char unused[17];
+//           cl_char bus;
+//           cl_char device;
+//           cl_char function;
+//       } pcie;
+//       struct { cl_uint type; cl_uint data[5]; } raw;
+//   } cl_device_topology_amd;
+//
+// Both arms are 24 bytes (4 + 20). `bus` sits after the 4-byte `type` and
+// the 17 unused bytes, i.e. at byte offset 21 of the 24-byte union.
+const AMD_TOPOLOGY_SIZE: usize = 24;
+const AMD_TOPOLOGY_BUS_OFFSET: usize = 21;
+
 pub fn get_bus_id(d: Device) -> GPUResult<u32> {
-    let result = d.info_raw(0x4008)?;
+    if device_vendor(d)? == Vendor::Amd {
+        let topology = d.info_raw(AMD_TOPOLOGY_AMD_INFO)?;
+        debug_assert_eq!(
+            topology.len(),
+            AMD_TOPOLOGY_SIZE,
+            "CL_DEVICE_TOPOLOGY_AMD returned {} bytes, not the {}-byte cl_device_topology_amd \
+             union from cl_ext.h -- the bus-id offset above is no longer valid",
+            topology.len(),
+            AMD_TOPOLOGY_SIZE,
+        );
+        return Ok(topology[AMD_TOPOLOGY_BUS_OFFSET] as u32);
+    }
+
+    let result = d.info_raw(NVIDIA_BUS_ID_INFO)?;
     Ok((result[0] as u32)
         + ((result[1] as u32) << 8)
         + ((result[2] as u32) << 16)
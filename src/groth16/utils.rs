@@ -1,3 +1,4 @@
+use std::collections::VecDeque;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
 use ff::PrimeField;
@@ -52,8 +53,17 @@ impl<'a, E: Engine, F: Fn(usize) -> <E::Fr as PrimeField>::Repr + Sync + Send>
 pub trait MultiscalarPrecomp<E: Engine>: Send + Sync {
     fn window_size(&self) -> usize;
     fn window_mask(&self) -> u64;
-    fn tables(&self) -> &[Vec<E::G1Affine>];
+    /// Number of multiples stored per point (the table width).
+    fn table_entries(&self) -> usize;
+    /// The precomputed multiples of point `idx`, as a contiguous slice into
+    /// the backing flat table.
+    fn point_table(&self, idx: usize) -> &[E::G1Affine];
     fn at_point(&self, idx: usize) -> MultiscalarPrecompRef<'_, E>;
+    /// Whether `point_table` stores only the positive multiples of each
+    /// base (see `precompute_signed_window`), requiring `multiscalar` to
+    /// Booth-recode scan digits and negate the looked-up point for
+    /// negative digits.
+    fn signed(&self) -> bool;
 }
 
 #[derive(Debug)]
@@ -62,7 +72,13 @@ pub struct MultiscalarPrecompOwned<E: Engine> {
     window_size: usize,
     window_mask: u64,
     table_entries: usize,
-    tables: Vec<Vec<E::G1Affine>>,
+    // A single flat, coalesced allocation: point `m`'s multiples live at
+    // `table[m * table_entries .. (m + 1) * table_entries]`. This avoids the
+    // pointer-chasing of a `Vec<Vec<_>>`, where every point's table is its
+    // own scattered heap allocation, so the scan in `multiscalar` walks
+    // contiguous memory instead of camping on cache-line-sized partitions.
+    table: Vec<E::G1Affine>,
+    signed: bool,
 }
 
 impl<E: Engine> MultiscalarPrecomp<E> for MultiscalarPrecompOwned<E> {
@@ -74,8 +90,13 @@ impl<E: Engine> MultiscalarPrecomp<E> for MultiscalarPrecompOwned<E> {
         self.window_mask
     }
 
-    fn tables(&self) -> &[Vec<E::G1Affine>] {
-        &self.tables
+    fn table_entries(&self) -> usize {
+        self.table_entries
+    }
+
+    fn point_table(&self, idx: usize) -> &[E::G1Affine] {
+        let base = idx * self.table_entries;
+        &self.table[base..base + self.table_entries]
     }
 
     fn at_point(&self, idx: usize) -> MultiscalarPrecompRef<'_, E> {
@@ -84,9 +105,14 @@ impl<E: Engine> MultiscalarPrecomp<E> for MultiscalarPrecompOwned<E> {
             window_size: self.window_size,
             window_mask: self.window_mask,
             table_entries: self.table_entries,
-            tables: &self.tables[idx..],
+            table: &self.table[idx * self.table_entries..],
+            signed: self.signed,
         }
     }
+
+    fn signed(&self) -> bool {
+        self.signed
+    }
 }
 
 pub struct MultiscalarPrecompRef<'a, E: Engine> {
@@ -94,7 +120,8 @@ pub struct MultiscalarPrecompRef<'a, E: Engine> {
     window_size: usize,
     window_mask: u64,
     table_entries: usize,
-    tables: &'a [Vec<E::G1Affine>],
+    table: &'a [E::G1Affine],
+    signed: bool,
 }
 
 impl<E: Engine> MultiscalarPrecomp<E> for MultiscalarPrecompRef<'_, E> {
@@ -106,8 +133,13 @@ impl<E: Engine> MultiscalarPrecomp<E> for MultiscalarPrecompRef<'_, E> {
         self.window_mask
     }
 
-    fn tables(&self) -> &[Vec<E::G1Affine>] {
-        self.tables
+    fn table_entries(&self) -> usize {
+        self.table_entries
+    }
+
+    fn point_table(&self, idx: usize) -> &[E::G1Affine] {
+        let base = idx * self.table_entries;
+        &self.table[base..base + self.table_entries]
     }
 
     fn at_point(&self, idx: usize) -> MultiscalarPrecompRef<'_, E> {
@@ -116,9 +148,14 @@ impl<E: Engine> MultiscalarPrecomp<E> for MultiscalarPrecompRef<'_, E> {
             window_size: self.window_size,
             window_mask: self.window_mask,
             table_entries: self.table_entries,
-            tables: &self.tables[idx..],
+            table: &self.table[idx * self.table_entries..],
+            signed: self.signed,
         }
     }
+
+    fn signed(&self) -> bool {
+        self.signed
+    }
 }
 
 /// Precompute tables for fixed bases.
@@ -129,9 +166,51 @@ pub fn precompute_fixed_window<E: Engine>(
     let table_entries = (1 << window_size) - 1;
     let num_points = points.len();
 
-    let tables = points
+    let table: Vec<E::G1Affine> = points
+        .into_par_iter()
+        .flat_map(|point| {
+            let mut table = Vec::with_capacity(table_entries);
+            table.push(*point);
+
+            let mut cur_precomp_point = point.into_projective();
+
+            for _ in 1..table_entries {
+                cur_precomp_point.add_assign_mixed(point);
+                table.push(cur_precomp_point.into_affine());
+            }
+
+            table
+        })
+        .collect();
+
+    MultiscalarPrecompOwned {
+        num_points,
+        window_size,
+        window_mask: (1 << window_size) - 1,
+        table_entries,
+        table,
+        signed: false,
+    }
+}
+
+/// Precompute tables for fixed bases using signed-digit windows.
+///
+/// Only the `2^(window_size-1)` positive multiples of each point are
+/// stored. At scan time, `multiscalar` Booth-recodes window digits into
+/// `[-2^(window_size-1), 2^(window_size-1)]` and negates the looked-up
+/// point for negative digits (cheap in G1: just a y-coordinate negation),
+/// so this halves both the table memory and the precompute additions of
+/// `precompute_fixed_window`.
+pub fn precompute_signed_window<E: Engine>(
+    points: &[E::G1Affine],
+    window_size: usize,
+) -> MultiscalarPrecompOwned<E> {
+    let table_entries = 1 << (window_size - 1);
+    let num_points = points.len();
+
+    let table: Vec<E::G1Affine> = points
         .into_par_iter()
-        .map(|point| {
+        .flat_map(|point| {
             let mut table = Vec::with_capacity(table_entries);
             table.push(*point);
 
@@ -151,10 +230,16 @@ pub fn precompute_fixed_window<E: Engine>(
         window_size,
         window_mask: (1 << window_size) - 1,
         table_entries,
-        tables,
+        table,
+        signed: true,
     }
 }
 
+/// How many points ahead `multiscalar`'s unsigned path prefetches the next
+/// table entry, overlapping its load with the current points' projective
+/// additions instead of stalling on each one in turn.
+const PREFETCH_DISTANCE: usize = 4; // TUNEABLE
+
 /// Multipoint scalar multiplication
 /// Only supports window sizes that evenly divide a limb and nbits!!
 fn multiscalar<E: Engine>(
@@ -170,13 +255,18 @@ fn multiscalar<E: Engine>(
         panic!("Unsupported multiscalar window size!");
     }
 
+    if precomp_table.signed() {
+        return multiscalar_signed(k, precomp_table, num_points, nbits);
+    }
+
     let mut result = E::G1::zero();
 
     // nbits must be evenly divided by window_size!
     let num_windows = (nbits + precomp_table.window_size() - 1) / precomp_table.window_size();
-    let mut idx;
 
-    // This version prefetches the next window and computes on the previous window.
+    // This version prefetches PREFETCH_DISTANCE points ahead and computes on
+    // the points that far behind, so the prefetched loads have time to land
+    // before the projective addition that needs them runs.
     for i in (0..num_windows).rev() {
         const BITS_PER_LIMB: usize = std::mem::size_of::<u64>() * 8;
         let limb = (i * precomp_table.window_size()) / BITS_PER_LIMB;
@@ -185,39 +275,297 @@ fn multiscalar<E: Engine>(
         for _ in 0..precomp_table.window_size() {
             result.double();
         }
-        let mut prev_idx = 0;
-        let mut prev_table: &Vec<E::G1Affine> = &precomp_table.tables()[0];
-        let mut table: &Vec<E::G1Affine> = &precomp_table.tables()[0];
+
+        let mut pending: VecDeque<(u64, &[E::G1Affine])> = VecDeque::with_capacity(PREFETCH_DISTANCE);
         for m in 0..num_points {
-            idx = (AsRef::<[u64]>::as_ref(&k[m]))[limb]
+            let idx = (AsRef::<[u64]>::as_ref(&k[m]))[limb]
                 >> (window_in_limb * precomp_table.window_size())
                 & precomp_table.window_mask();
+            let table = precomp_table.point_table(m);
             if idx > 0 {
-                table = &precomp_table.tables()[m];
                 prefetch(&table[idx as usize - 1]);
             }
-            if prev_idx > 0 && m > 0 {
+
+            pending.push_back((idx, table));
+            if pending.len() > PREFETCH_DISTANCE {
+                let (prev_idx, prev_table) = pending.pop_front().unwrap();
+                if prev_idx > 0 {
+                    result.add_assign_mixed(&prev_table[prev_idx as usize - 1]);
+                }
+            }
+        }
+        // Drain the points still in flight once there's nothing left to prefetch.
+        for (prev_idx, prev_table) in pending {
+            if prev_idx > 0 {
                 result.add_assign_mixed(&prev_table[prev_idx as usize - 1]);
             }
-            prev_idx = idx;
-            prev_table = table;
         }
-        // Perform the final addition
-        if prev_idx > 0 {
-            result.add_assign_mixed(&prev_table[prev_idx as usize - 1]);
+    }
+
+    result
+}
+
+/// Add `digit * P` to `result`, where `table[j]` holds `(j + 1) * P` and
+/// `digit` is a non-zero Booth-recoded digit from `multiscalar_signed`:
+/// `table[|digit| - 1]` is added, negated first when `digit` is negative.
+fn add_signed_digit<E: Engine>(result: &mut E::G1, table: &[E::G1Affine], digit: i64) {
+    if digit > 0 {
+        result.add_assign_mixed(&table[digit as usize - 1]);
+    } else {
+        let mut neg = table[(-digit) as usize - 1];
+        neg.negate();
+        result.add_assign_mixed(&neg);
+    }
+}
+
+/// Multiscalar multiplication against a signed-window precompute table (see
+/// `precompute_signed_window`). Scalars are first Booth-recoded into signed
+/// digits with carries propagated to the next window, then accumulated
+/// top-down the same way as the unsigned path. One extra window past
+/// `nbits / window_size` absorbs a carry out of the top window.
+fn multiscalar_signed<E: Engine>(
+    k: &[<E::Fr as ff::PrimeField>::Repr],
+    precomp_table: &dyn MultiscalarPrecomp<E>,
+    num_points: usize,
+    nbits: usize,
+) -> E::G1 {
+    let window_size = precomp_table.window_size();
+    let num_windows = (nbits + window_size - 1) / window_size + 1;
+
+    let digits: Vec<Vec<i64>> = k[..num_points]
+        .iter()
+        .map(|s| recode_scalar(s, window_size, num_windows))
+        .collect();
+
+    let mut result = E::G1::zero();
+    for i in (0..num_windows).rev() {
+        for _ in 0..window_size {
+            result.double();
+        }
+
+        let mut prev: Option<(usize, i64)> = None;
+        for m in 0..num_points {
+            let digit = digits[m][i];
+            if digit != 0 {
+                let table = precomp_table.point_table(m);
+                prefetch(&table[digit.unsigned_abs() as usize - 1]);
+            }
+            if let Some((prev_m, prev_digit)) = prev {
+                if prev_digit != 0 {
+                    add_signed_digit::<E>(&mut result, precomp_table.point_table(prev_m), prev_digit);
+                }
+            }
+            prev = Some((m, digit));
+        }
+        if let Some((prev_m, prev_digit)) = prev {
+            if prev_digit != 0 {
+                add_signed_digit::<E>(&mut result, precomp_table.point_table(prev_m), prev_digit);
+            }
         }
     }
 
     result
 }
 
+/// Pick a bucket-window width for Pippenger's algorithm given the number of
+/// points being summed. Larger point sets amortize bigger windows (fewer,
+/// cheaper passes) while small ones are better off with a narrow window
+/// since the bucket-table overhead dominates.
+fn bucket_window_size(num_points: usize) -> usize {
+    if num_points < 32 {
+        3
+    } else {
+        (num_points as f64).ln().ceil() as usize
+    }
+}
+
+/// Extract `num_bits` bits starting at `bit_offset` from a scalar's limbs,
+/// returning them as the low bits of a `u64`. Bits past the end of the
+/// representation are treated as zero.
+fn get_bits(repr: &impl AsRef<[u64]>, bit_offset: usize, num_bits: usize) -> u64 {
+    let limbs = repr.as_ref();
+    let mut result = 0u64;
+    for i in 0..num_bits {
+        let bit_idx = bit_offset + i;
+        let limb_idx = bit_idx / 64;
+        if limb_idx >= limbs.len() {
+            break;
+        }
+        let bit = (limbs[limb_idx] >> (bit_idx % 64)) & 1;
+        result |= bit << i;
+    }
+    result
+}
+
+/// Recode a scalar into `num_windows` signed digits of `c` bits each, in the
+/// range `[-2^(c-1), 2^(c-1)]`. A window whose raw value overflows `2^(c-1)`
+/// borrows from the next window instead, which is why callers reserve one
+/// extra window past `nbits / c` to absorb a carry out of the top window.
+fn recode_scalar(repr: &impl AsRef<[u64]>, c: usize, num_windows: usize) -> Vec<i64> {
+    let mut digits = Vec::with_capacity(num_windows);
+    let mut carry = 0i64;
+    for w in 0..num_windows {
+        let raw = get_bits(repr, w * c, c) as i64 + carry;
+        if raw > (1i64 << (c - 1)) {
+            digits.push(raw - (1i64 << c));
+            carry = 1;
+        } else {
+            digits.push(raw);
+            carry = 0;
+        }
+    }
+    digits
+}
+
+/// Sum one window's contribution across all points using Pippenger's bucket
+/// method: each point is dropped into the bucket for its (unsigned) digit
+/// magnitude, negating the point first when the digit is negative, and the
+/// buckets are combined with a running-sum sweep from the top bucket down.
+fn accumulate_bucket_window<E: Engine>(
+    points: &[E::G1Affine],
+    digits: &[Vec<i64>],
+    window: usize,
+    c: usize,
+) -> E::G1 {
+    let num_buckets = 1 << (c - 1);
+    let mut buckets = vec![E::G1::zero(); num_buckets];
+
+    for (point, scalar_digits) in points.iter().zip(digits.iter()) {
+        let digit = scalar_digits[window];
+        if digit == 0 {
+            continue;
+        }
+        if digit > 0 {
+            buckets[digit as usize - 1].add_assign_mixed(point);
+        } else {
+            let mut neg = *point;
+            neg.negate();
+            buckets[(-digit) as usize - 1].add_assign_mixed(&neg);
+        }
+    }
+
+    let mut running = E::G1::zero();
+    let mut acc = E::G1::zero();
+    for bucket in buckets.into_iter().rev() {
+        running.add_assign(&bucket);
+        acc.add_assign(&running);
+    }
+    acc
+}
+
+/// Variable-base multiscalar multiplication via Pippenger's bucket method.
+///
+/// Unlike `multiscalar`/`precompute_fixed_window`, this needs no precomputed
+/// per-point table, which makes it the better choice when the bases change
+/// on every call (e.g. the `G1Affine`s in a one-shot proof) rather than
+/// staying fixed across many calls.
+pub fn variable_base_multiscalar<E: Engine>(
+    points: &[E::G1Affine],
+    scalars: &[<E::Fr as PrimeField>::Repr],
+) -> E::G1 {
+    assert_eq!(points.len(), scalars.len());
+    if points.is_empty() {
+        return E::G1::zero();
+    }
+
+    let c = bucket_window_size(points.len());
+    let nbits = <E::Fr as PrimeField>::NUM_BITS as usize;
+    // One extra window past `nbits / c` absorbs a carry out of the top window.
+    let num_windows = (nbits + c - 1) / c + 1;
+
+    let digits: Vec<Vec<i64>> = scalars
+        .par_iter()
+        .map(|s| recode_scalar(s, c, num_windows))
+        .collect();
+
+    let window_sums: Vec<E::G1> = POOL.install(|| {
+        (0..num_windows)
+            .into_par_iter()
+            .map(|w| accumulate_bucket_window::<E>(points, &digits, w, c))
+            .collect()
+    });
+
+    window_sums
+        .into_iter()
+        .rev()
+        .fold(E::G1::zero(), |mut acc, window_sum| {
+            for _ in 0..c {
+                acc.double();
+            }
+            acc.add_assign(&window_sum);
+            acc
+        })
+}
+
+/// Fraction of a device's available memory that `auto_tune` will let a
+/// `precompute_fixed_window` table occupy.
+const TABLE_MEMORY_FRACTION: f64 = 0.7; // TUNEABLE
+
+/// A `window_size`/`chunk_size` pair sized to fit a target device's memory.
+#[derive(Debug, Clone, Copy)]
+pub struct AutoTuning {
+    pub window_size: usize,
+    pub chunk_size: usize,
+}
+
+/// Pick the largest `window_size` that `multiscalar` accepts (1, 2, 4, 8 or
+/// 16) whose `precompute_fixed_window` table footprint
+/// (`num_points * ((1 << w) - 1) * size_of::<G1Affine>()`) fits within
+/// `TABLE_MEMORY_FRACTION` of `available_memory`, or 1 if none fits, then
+/// size `chunk_size` off whatever memory is left over for scalar buffers.
+///
+/// Without this, large base sets silently risk OOM on memory-constrained
+/// cards while leaving memory (and throughput) on the table on big ones.
+pub fn auto_tune<E: Engine>(num_points: usize, available_memory: u64) -> AutoTuning {
+    let point_size = std::mem::size_of::<E::G1Affine>() as u64;
+    let table_budget = (available_memory as f64 * TABLE_MEMORY_FRACTION) as u64;
+
+    let mut window_size = 1;
+    for &w in &[1usize, 2, 4, 8, 16] {
+        let entries = (1u64 << w) - 1;
+        let footprint = (num_points as u64) * entries * point_size;
+        if footprint > table_budget {
+            break;
+        }
+        window_size = w;
+    }
+
+    let table_footprint = (num_points as u64) * ((1u64 << window_size) - 1) * point_size;
+    let remaining = available_memory.saturating_sub(table_footprint);
+    let scalar_size = std::mem::size_of::<<E::Fr as PrimeField>::Repr>() as u64;
+    let chunk_size = (remaining / scalar_size.max(1)).clamp(1, num_points.max(1) as u64) as usize;
+
+    AutoTuning {
+        window_size,
+        chunk_size,
+    }
+}
+
+/// `precompute_fixed_window`, but with `window_size` chosen automatically
+/// from `device`'s available memory (via `auto_tune` and `gpu::utils::get_memory`)
+/// instead of a caller-supplied constant.
+#[cfg(feature = "gpu")]
+pub fn precompute_fixed_window_auto<E: Engine>(
+    points: &[E::G1Affine],
+    device: ocl::Device,
+) -> crate::gpu::error::GPUResult<MultiscalarPrecompOwned<E>> {
+    let memory = crate::gpu::utils::get_memory(device)?;
+    let tuning = auto_tune::<E>(points.len(), memory);
+    Ok(precompute_fixed_window(points, tuning.window_size))
+}
+
 /// Perform a threaded multiscalar multiplication and accumulation.
+///
+/// `chunk_size_hint` overrides the default chunk-size heuristic below; pass
+/// `None` for the default, or `Some(auto_tune(..).chunk_size)` to size the
+/// chunk off a target device's memory instead.
 pub fn par_multiscalar<F, E: Engine>(
     max_threads: usize,
     k: &PublicInputs<'_, E, F>,
     precomp_table: &dyn MultiscalarPrecomp<E>,
     num_points: usize,
     nbits: usize,
+    chunk_size_hint: Option<usize>,
 ) -> E::G1
 where
     F: Fn(usize) -> <E::Fr as PrimeField>::Repr + Sync + Send,
@@ -228,8 +576,8 @@ where
     // threads because threads sometimes get preempted. When that happens
     // these long pole threads hold up progress across the board resulting in
     // occasional long delays.
-    let mut chunk_size = 16; // TUNEABLE
-    if num_points > 1024 {
+    let mut chunk_size = chunk_size_hint.unwrap_or(16); // TUNEABLE
+    if chunk_size_hint.is_none() && num_points > 1024 {
         chunk_size = 256;
     }
     if chunk_size > num_points {
@@ -291,4 +639,113 @@ where
     }
 
     result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bls::{Bls12, Fr};
+    use ff::Field;
+    use rand::thread_rng;
+
+    /// `Σ sᵢ·Pᵢ` computed one term at a time, with no bucket/window
+    /// machinery, as the ground truth for the optimized paths under test.
+    fn naive_msm(points: &[<Bls12 as Engine>::G1Affine], scalars: &[Fr]) -> <Bls12 as Engine>::G1 {
+        let mut acc = <Bls12 as Engine>::G1::zero();
+        for (point, scalar) in points.iter().zip(scalars.iter()) {
+            let mut term = point.into_projective();
+            term.mul_assign(scalar.into_repr());
+            acc.add_assign(&term);
+        }
+        acc
+    }
+
+    #[test]
+    fn variable_base_matches_naive_msm() {
+        let rng = &mut thread_rng();
+        let num_points = 37; // not a power of two, to exercise the tail/carry paths
+        let points: Vec<_> = (0..num_points)
+            .map(|_| <Bls12 as Engine>::G1::random(rng).into_affine())
+            .collect();
+        let scalars: Vec<Fr> = (0..num_points).map(|_| Fr::random(rng)).collect();
+        let reprs: Vec<_> = scalars.iter().map(|s| s.into_repr()).collect();
+
+        let expected = naive_msm(&points, &scalars);
+        let actual = variable_base_multiscalar::<Bls12>(&points, &reprs);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn variable_base_handles_top_window_carry() {
+        // 64 points give c = 5. The scalar -1 = r - 1 has bits 245..=249 and
+        // 252..=254 set, so the top window holding scalar bits (250..=254)
+        // recodes to a negative digit and carries out into the extra slot
+        // `num_windows` reserves for it.
+        let rng = &mut thread_rng();
+        let num_points = 64;
+        let points: Vec<_> = (0..num_points)
+            .map(|_| <Bls12 as Engine>::G1::random(rng).into_affine())
+            .collect();
+        let mut max_scalar = Fr::one();
+        max_scalar.negate();
+        let scalars = vec![max_scalar; num_points];
+        let reprs: Vec<_> = scalars.iter().map(|s| s.into_repr()).collect();
+
+        let expected = naive_msm(&points, &scalars);
+        let actual = variable_base_multiscalar::<Bls12>(&points, &reprs);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn signed_window_matches_unsigned_window() {
+        let rng = &mut thread_rng();
+        let num_points = 16;
+        let window_size = 4;
+        let nbits = 256; // must be a multiple of window_size, per multiscalar's assertion
+
+        let points: Vec<_> = (0..num_points)
+            .map(|_| <Bls12 as Engine>::G1::random(rng).into_affine())
+            .collect();
+        let scalars: Vec<Fr> = (0..num_points).map(|_| Fr::random(rng)).collect();
+        let reprs: Vec<_> = scalars.iter().map(|s| s.into_repr()).collect();
+
+        let unsigned_table = precompute_fixed_window::<Bls12>(&points, window_size);
+        let signed_table = precompute_signed_window::<Bls12>(&points, window_size);
+
+        let unsigned_result = multiscalar::<Bls12>(&reprs, &unsigned_table, num_points, nbits);
+        let signed_result = multiscalar::<Bls12>(&reprs, &signed_table, num_points, nbits);
+
+        assert_eq!(signed_result, unsigned_result);
+    }
+
+    #[test]
+    fn signed_window_handles_top_window_carry() {
+        // An all-ones scalar forces every window's raw digit above
+        // 2^(window_size-1), so the carry propagates through every window,
+        // including out of the top one into the extra slot `multiscalar_signed`
+        // reserves for it.
+        let rng = &mut thread_rng();
+        let num_points = 8;
+        let window_size = 4;
+        let nbits = 256;
+
+        let points: Vec<_> = (0..num_points)
+            .map(|_| <Bls12 as Engine>::G1::random(rng).into_affine())
+            .collect();
+        let mut all_ones = <Fr as PrimeField>::Repr::default();
+        for limb in all_ones.as_mut() {
+            *limb = u64::MAX;
+        }
+        let reprs = vec![all_ones; num_points];
+
+        let unsigned_table = precompute_fixed_window::<Bls12>(&points, window_size);
+        let signed_table = precompute_signed_window::<Bls12>(&points, window_size);
+
+        let unsigned_result = multiscalar::<Bls12>(&reprs, &unsigned_table, num_points, nbits);
+        let signed_result = multiscalar::<Bls12>(&reprs, &signed_table, num_points, nbits);
+
+        assert_eq!(signed_result, unsigned_result);
+    }
 }
\ No newline at end of file